@@ -0,0 +1,94 @@
+//! Bounded edit-distance ("fuzzy") search: find every stored key within a
+//! given Levenshtein distance of a query, along with that distance.
+use std::hash::Hash;
+
+use crate::{Node, Trie};
+
+impl<Label: Eq + Hash + Copy, Value> Trie<Label, Value> {
+    /// Returns every stored key within Levenshtein distance `max_dist` of `query`,
+    /// together with its distance.
+    ///
+    /// This is a DFS over the trie carrying a DP row of length `query.len() + 1`:
+    /// the root row is `[0, 1, 2, ..., n]` and descending an edge labeled `c`
+    /// produces a new row where `new[0] = prev[0] + 1` and, for `i in 1..=n`,
+    /// `cost = if query[i - 1] == c { 0 } else { 1 }` and
+    /// `new[i] = min(new[i - 1] + 1, prev[i] + 1, prev[i - 1] + cost)`. A subtree is
+    /// pruned as soon as `min(new) > max_dist`, since edit distance only grows with
+    /// depth.
+    /// ```
+    /// use trie_rs::TrieBuilder;
+    ///
+    /// let mut builder = TrieBuilder::new();
+    /// builder.push(vec!['c', 'a', 't'], ());
+    /// builder.push(vec!['c', 'a', 'r', 't'], ());
+    /// builder.push(vec!['d', 'o', 'g'], ());
+    /// let trie = builder.build();
+    /// let mut hits = trie.fuzzy_search(&vec!['c', 'a', 't'], 1);
+    /// hits.sort();
+    /// assert_eq!(hits, vec![
+    ///     (vec!['c', 'a', 'r', 't'], 1),
+    ///     (vec!['c', 'a', 't'], 0),
+    /// ]);
+    /// ```
+    pub fn fuzzy_search(&self, query: &[Label], max_dist: usize) -> Vec<(Vec<Label>, usize)> {
+        let root_row: Vec<usize> = (0..=query.len()).collect();
+        let mut results = vec![];
+        if self.root.value.is_some() {
+            if let Some(&dist) = root_row.last() {
+                if dist <= max_dist {
+                    results.push((vec![], dist));
+                }
+            }
+        }
+        let mut prefix = vec![];
+        fuzzy_search_node(&self.root, query, max_dist, &root_row, &mut prefix, &mut results);
+        results
+    }
+}
+
+fn edit_distance_row<Label: Eq + Copy>(prev: &[usize], query: &[Label], label: Label) -> Vec<usize> {
+    let n = query.len();
+    let mut row = vec![0; n + 1];
+    row[0] = prev[0] + 1;
+    for i in 1..=n {
+        let cost = if query[i - 1] == label { 0 } else { 1 };
+        row[i] = (row[i - 1] + 1).min(prev[i] + 1).min(prev[i - 1] + cost);
+    }
+    row
+}
+
+fn fuzzy_search_node<Label: Eq + Hash + Copy, Value>(
+    node: &Node<Label, Value>,
+    query: &[Label],
+    max_dist: usize,
+    row: &[usize],
+    prefix: &mut Vec<Label>,
+    results: &mut Vec<(Vec<Label>, usize)>,
+) {
+    for (&label, child) in node.children.iter() {
+        let base_len = prefix.len();
+        prefix.push(label);
+        let mut current_row = edit_distance_row(row, query, label);
+        let mut pruned = *current_row.iter().min().unwrap() > max_dist;
+        if !pruned {
+            for &segment_label in &child.segment {
+                prefix.push(segment_label);
+                current_row = edit_distance_row(&current_row, query, segment_label);
+                if *current_row.iter().min().unwrap() > max_dist {
+                    pruned = true;
+                    break;
+                }
+            }
+        }
+        if !pruned {
+            if child.value.is_some() {
+                let dist = *current_row.last().unwrap();
+                if dist <= max_dist {
+                    results.push((prefix.clone(), dist));
+                }
+            }
+            fuzzy_search_node(child, query, max_dist, &current_row, prefix, results);
+        }
+        prefix.truncate(base_len);
+    }
+}
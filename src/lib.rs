@@ -2,29 +2,58 @@
 //!  - `common_prefix_search`: give every item in the trie that is
 //!  a prefix of the query
 //!  - `search`: give every item in the trie that would follow the query
-//!  In order for search to be fast, an index has to be built, which can
-//!  become very large if you intend to store many items in the trie.
-//!  PR welcomes to store partial indexes for large tries.
+//!  `search` walks the matching subtree lazily (see `search_iter`), so
+//!  there is no precomputed index to build or to keep in memory.
+//!
+//! Besides plain membership, the trie associates a `Value` with every
+//! key, which turns it into a map keyed by `Label` sequences. Use `()` as
+//! `Value` when you only care about membership.
+//!
+//! With the `serde` feature enabled, `Trie`, `Node` and `TrieError` derive
+//! `Serialize`/`Deserialize`, so a built trie can be persisted with e.g.
+//! `bincode::serialize(&trie)` and reloaded later without paying
+//! construction costs again.
+//!
+//! `TrieBuilder::compressed` opts into a radix/PATRICIA-style trie: chains
+//! of single-child, non-terminal nodes are collapsed into one edge when
+//! `build()` runs, trading a bit of lookup complexity for fewer nodes on
+//! sparse, long-key data. Plain callers keep the one-`Label`-per-node trie.
+//!
+//! `fuzzy_search` (see the `fuzzy` module) additionally finds every stored
+//! key within a bounded Levenshtein distance of a query, for
+//! spell-correction/autocomplete style lookups.
+//!
+//! `Trie::build_stream_matcher` (see `StreamMatcher`) builds an
+//! Aho-Corasick-style automaton over the stored keys so a continuous
+//! stream of labels can be scanned for every occurrence without
+//! re-running `search` from scratch at each position.
+//!
+//! Every query except `fuzzy_search` (which takes a `&[Label]` slice) takes
+//! `impl IntoIterator<Item = Label>`, so callers can feed a `Vec`, an array, or
+//! e.g. a `Chars` iterator directly without collecting into a slice first.
 //!
 //! ```
 //! use trie_rs::TrieBuilder;
 //!
-//! let build_index = true;
-//! let mut builder = TrieBuilder::new(build_index);
-//! builder.push(&vec!['A', 'l', 'a', 'b', 'a', 'm', 'a']);
-//! builder.push(&vec!['A', 'l', 'a', 's', 'k', 'a']);
-//! builder.push(&vec!['A', 'l', 'a', 's']);
+//! let mut builder = TrieBuilder::new();
+//! builder.push(vec!['A', 'l', 'a', 'b', 'a', 'm', 'a'], "Montgomery");
+//! builder.push(vec!['A', 'l', 'a', 's', 'k', 'a'], "Juneau");
+//! builder.push(vec!['A', 'l', 'a', 's'], "not a state");
 //! let trie = builder.build();
-//! assert_eq!(trie.search(&vec!['A', 'l', 'a', 's']).unwrap(),
-//! &vec![
+//! assert_eq!(trie.search(vec!['A', 'l', 'a', 's']).unwrap(),
+//! vec![
 //!     vec!['A', 'l', 'a', 's'],
 //!     vec!['A', 'l', 'a', 's', 'k', 'a'],
 //! ]);
-//! assert_eq!(trie.common_prefix_search(&vec!['A', 'l', 'a', 's', 'k', 'a']),
+//! assert_eq!(trie.common_prefix_search(vec!['A', 'l', 'a', 's', 'k', 'a']),
 //! vec![
 //!     vec!['A', 'l', 'a', 's'],
 //!     vec!['A', 'l', 'a', 's', 'k', 'a'],
 //! ]);
+//! assert_eq!(trie.get(vec!['A', 'l', 'a', 'b', 'a', 'm', 'a']), Some(&"Montgomery"));
+//! assert_eq!(trie.contains_key(vec!['A', 'l', 'a']), false);
+//! assert_eq!(trie.find_longest_prefix(vec!['A', 'l', 'a', 's', 'k', 'a', '!']),
+//! Some(vec!['A', 'l', 'a', 's', 'k', 'a']));
 //! ```
 //!
 //! The item stored in the Trie needs eq + Hash as under the hood we use
@@ -35,172 +64,462 @@
 use std::collections::HashMap;
 use std::hash::Hash;
 
+mod fuzzy;
+mod stream;
+
+pub use stream::StreamMatcher;
+
 #[derive(Debug, Clone)]
-pub struct TrieBuilder<Label> {
-    build_search_index: bool,
-    trie: Trie<Label>,
+pub struct TrieBuilder<Label, Value> {
+    compressed: bool,
+    trie: Trie<Label, Value>,
 }
 
-impl<Label: Eq + Hash + Copy> TrieBuilder<Label> {
-    pub fn new(build_search_index: bool) -> Self {
+impl<Label: Eq + Hash + Copy, Value> TrieBuilder<Label, Value> {
+    pub fn new() -> Self {
         Self {
-            build_search_index,
-            trie: Trie::<Label>::default(),
+            compressed: false,
+            trie: Trie::<Label, Value>::default(),
         }
     }
 
-    pub fn push(&mut self, element: &[Label]) {
-        self.trie.push(element);
+    /// Collapses chains of single-child, non-terminal nodes into one multi-label
+    /// edge when `build()` runs (see the module docs). Off by default.
+    pub fn compressed(mut self, compressed: bool) -> Self {
+        self.compressed = compressed;
+        self
+    }
+
+    pub fn push(&mut self, element: impl IntoIterator<Item = Label>, value: Value) {
+        self.trie.push(element, value);
     }
 
-    pub fn build(mut self) -> Trie<Label> {
-        if self.build_search_index {
-            self.trie.build_index();
+    pub fn build(mut self) -> Trie<Label, Value> {
+        if self.compressed {
+            self.trie.compress();
         }
         self.trie
     }
 }
 
+impl<Label: Eq + Hash + Copy, Value> Default for TrieBuilder<Label, Value> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TrieError {
-    /// Attempt to use search on a trie, that did not build the index.
-    /// Run trie.build_index() first.
-    IndexNotBuilt,
     /// Your trie does not have any result for this search.
     NoResultFound,
 }
 
 #[derive(Debug, Clone)]
-pub struct Trie<Label> {
-    has_search_index: bool,
-    root: Node<Label>,
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "Label: serde::Serialize, Value: serde::Serialize",
+        deserialize = "Label: Eq + std::hash::Hash + serde::Deserialize<'de>, Value: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Trie<Label, Value> {
+    pub(crate) root: Node<Label, Value>,
 }
 
-fn _build_index<Label: Eq + Hash + Copy>(
-    node: &mut Node<Label>,
-    current_words: &mut Vec<Vec<Label>>,
-    prefix: &mut Vec<Label>,
-) {
-    if node.is_leaf {
-        current_words.push(prefix.to_vec());
+impl<Label: Eq + Hash + Copy, Value> Trie<Label, Value> {
+    pub fn push(&mut self, element: impl IntoIterator<Item = Label>, value: Value) {
+        let mut node = &mut self.root;
+        for label in element {
+            node = node.children.entry(label).or_insert_with(Node::default);
+        }
+        node.value = Some(value);
     }
-    for (label, mut child) in node.children.iter_mut() {
-        prefix.push(*label);
-        let mut new_words = vec![];
-        _build_index(&mut child, &mut new_words, prefix);
-        current_words.extend(new_words);
-        prefix.pop();
+
+    /// Returns the value associated with `element`, if it was pushed to the trie.
+    pub fn get(&self, element: impl IntoIterator<Item = Label>) -> Option<&Value> {
+        let mut node = &self.root;
+        let mut iter = element.into_iter();
+        while let Some(label) = iter.next() {
+            node = node.children.get(&label)?;
+            for &segment_label in &node.segment {
+                if iter.next() != Some(segment_label) {
+                    return None;
+                }
+            }
+        }
+        node.value.as_ref()
+    }
+
+    /// Returns a mutable reference to the value associated with `element`, if any.
+    pub fn get_mut(&mut self, element: impl IntoIterator<Item = Label>) -> Option<&mut Value> {
+        let mut node = &mut self.root;
+        let mut iter = element.into_iter();
+        while let Some(label) = iter.next() {
+            node = node.children.get_mut(&label)?;
+            for &segment_label in &node.segment {
+                if iter.next() != Some(segment_label) {
+                    return None;
+                }
+            }
+        }
+        node.value.as_mut()
+    }
+
+    /// Collapses chains of single-child, non-terminal nodes into a single
+    /// multi-label edge, see `TrieBuilder::compressed`. Pushing to the trie
+    /// after compressing it is not supported.
+    pub fn compress(&mut self) {
+        compress_children(&mut self.root);
+    }
+
+    /// Builds a streaming multi-pattern matcher over the keys currently stored in
+    /// this trie, see `StreamMatcher`.
+    /// ```
+    /// use trie_rs::TrieBuilder;
+    ///
+    /// let mut builder = TrieBuilder::new();
+    /// builder.push(vec!['h', 'e'], ());
+    /// builder.push(vec!['s', 'h', 'e'], ());
+    /// let trie = builder.build();
+    /// let mut matcher = trie.build_stream_matcher();
+    /// assert_eq!(matcher.push('s'), Vec::<Vec<char>>::new());
+    /// let mut matches = matcher.push('h');
+    /// matches.sort();
+    /// assert_eq!(matches, Vec::<Vec<char>>::new());
+    /// let mut matches = matcher.push('e');
+    /// matches.sort();
+    /// assert_eq!(matches, vec![vec!['h', 'e'], vec!['s', 'h', 'e']]);
+    /// ```
+    pub fn build_stream_matcher(&self) -> StreamMatcher<Label> {
+        StreamMatcher::new(self)
     }
-    node.subwords = current_words.clone();
-}
 
-impl<Label: Eq + Hash + Copy> Trie<Label> {
-    /// Does a search within the trie in constant time (index is built ahead of time).
+    /// Returns whether `element` was pushed to the trie.
+    pub fn contains_key(&self, element: impl IntoIterator<Item = Label>) -> bool {
+        self.get(element).is_some()
+    }
+
+    /// Does a common prefix search in O(n) n being the number of labels in the query
     /// ```
     /// use trie_rs::TrieBuilder;
     ///
-    /// let build_index = true;
-    /// let mut builder = TrieBuilder::new(build_index);
-    /// builder.push(&vec!['A', 'l', 'a', 'b', 'a', 'm', 'a']);
-    /// builder.push(&vec!['A', 'l', 'a', 's', 'k', 'a']);
-    /// builder.push(&vec!['A', 'l', 'a', 's']);
+    /// let mut builder = TrieBuilder::new();
+    /// builder.push(vec!['A', 'l', 'a', 'b', 'a', 'm', 'a'], ());
+    /// builder.push(vec!['A', 'l', 'a', 's', 'k', 'a'], ());
+    /// builder.push(vec!['A', 'l', 'a', 's'], ());
     /// let trie = builder.build();
-    /// assert_eq!(trie.search(&vec!['A', 'l', 'a', 's']).unwrap(),
-    /// &vec![
+    /// assert_eq!(trie.common_prefix_search(vec!['A', 'l', 'a', 's', 'k', 'a']),
+    /// vec![
     ///     vec!['A', 'l', 'a', 's'],
     ///     vec!['A', 'l', 'a', 's', 'k', 'a'],
     /// ]);
     /// ```
-    pub fn search(&self, element: &[Label]) -> Result<&Vec<Vec<Label>>, TrieError> {
-        if !self.has_search_index {
-            return Err(TrieError::IndexNotBuilt);
-        }
+    pub fn common_prefix_search(&self, element: impl IntoIterator<Item = Label>) -> Vec<Vec<Label>> {
+        self.common_prefix_search_with_values(element)
+            .into_iter()
+            .map(|(key, _value)| key)
+            .collect()
+    }
+
+    /// Same as `common_prefix_search`, but also returns the value associated with every key.
+    pub fn common_prefix_search_with_values(
+        &self,
+        element: impl IntoIterator<Item = Label>,
+    ) -> Vec<(Vec<Label>, &Value)> {
         let mut node = &self.root;
-        for label in element.iter() {
-            let child_opt = node.children.get(label);
-            if let Some(child) = child_opt {
-                node = child;
-            } else {
-                return Err(TrieError::NoResultFound);
+        let mut results = vec![];
+        let mut prefix = vec![];
+        let mut iter = element.into_iter();
+        while let Some(label) = iter.next() {
+            let child = match node.children.get(&label) {
+                Some(child) => child,
+                None => return results,
+            };
+            prefix.push(label);
+            let mut diverged = false;
+            for &segment_label in &child.segment {
+                match iter.next() {
+                    Some(next_label) if next_label == segment_label => prefix.push(segment_label),
+                    _ => {
+                        diverged = true;
+                        break;
+                    }
+                }
+            }
+            if diverged {
+                return results;
+            }
+            node = child;
+            if let Some(value) = &node.value {
+                results.push((prefix.clone(), value));
             }
         }
-        Ok(&node.subwords)
+        results
     }
 
-    pub fn build_index(&mut self) {
-        // let node = &mut self.root;
-        let mut current_words = vec![];
+    /// Returns the longest stored key that is a prefix of `element` (the last
+    /// key hit while walking down `element`), the "longest match wins" lookup
+    /// used by tokenizers and routing tables.
+    /// ```
+    /// use trie_rs::TrieBuilder;
+    ///
+    /// let mut builder = TrieBuilder::new();
+    /// builder.push(vec!['A', 'l', 'a'], ());
+    /// builder.push(vec!['A', 'l', 'a', 's'], ());
+    /// builder.push(vec!['A', 'l', 'a', 's', 'k', 'a'], ());
+    /// let trie = builder.build();
+    /// assert_eq!(trie.find_longest_prefix(vec!['A', 'l', 'a', 's', 'k']),
+    /// Some(vec!['A', 'l', 'a', 's']));
+    /// ```
+    pub fn find_longest_prefix(&self, element: impl IntoIterator<Item = Label>) -> Option<Vec<Label>> {
+        let mut node = &self.root;
         let mut prefix = vec![];
-        _build_index(&mut self.root, &mut current_words, &mut prefix);
-
-        self.has_search_index = true;
-    }
-
-    pub fn push(&mut self, element: &[Label]) {
-        let mut node = &mut self.root;
-        for label in element.iter() {
-            node = node.children.entry(*label).or_insert_with(Node::default);
+        let mut longest = None;
+        let mut iter = element.into_iter();
+        while let Some(label) = iter.next() {
+            let child = match node.children.get(&label) {
+                Some(child) => child,
+                None => return longest,
+            };
+            prefix.push(label);
+            let mut diverged = false;
+            for &segment_label in &child.segment {
+                match iter.next() {
+                    Some(next_label) if next_label == segment_label => prefix.push(segment_label),
+                    _ => {
+                        diverged = true;
+                        break;
+                    }
+                }
+            }
+            if diverged {
+                return longest;
+            }
+            node = child;
+            if node.value.is_some() {
+                longest = Some(prefix.clone());
+            }
         }
-        node.is_leaf = true;
+        longest
     }
 
-    /// Does a common prefix search in O(n) n being the number of labels in the query
+    /// Does a search within the trie, yielding every key that has `element` as a
+    /// prefix (including `element` itself, if it was pushed). Unlike
+    /// `common_prefix_search`, which only collects the keys on the path from the
+    /// root to `element`, this walks the whole subtree below `element` and is lazy:
+    /// completions are produced one at a time by a DFS over the subtree, so no index
+    /// needs to be built ahead of time and the DFS stack only holds the not yet
+    /// visited children of the nodes on the current path, rather than every stored
+    /// entry.
     /// ```
     /// use trie_rs::TrieBuilder;
     ///
-    /// let build_index = false;
-    /// let mut builder = TrieBuilder::new(build_index);
-    /// builder.push(&vec!['A', 'l', 'a', 'b', 'a', 'm', 'a']);
-    /// builder.push(&vec!['A', 'l', 'a', 's', 'k', 'a']);
-    /// builder.push(&vec!['A', 'l', 'a', 's']);
+    /// let mut builder = TrieBuilder::new();
+    /// builder.push(vec!['A', 'l', 'a', 'b', 'a', 'm', 'a'], ());
+    /// builder.push(vec!['A', 'l', 'a', 's', 'k', 'a'], ());
+    /// builder.push(vec!['A', 'l', 'a', 's'], ());
     /// let trie = builder.build();
-    /// assert_eq!(trie.common_prefix_search(&vec!['A', 'l', 'a', 's', 'k', 'a']),
+    /// assert_eq!(trie.search(vec!['A', 'l', 'a', 's']).unwrap(),
     /// vec![
     ///     vec!['A', 'l', 'a', 's'],
     ///     vec!['A', 'l', 'a', 's', 'k', 'a'],
     /// ]);
     /// ```
-    pub fn common_prefix_search(&self, element: &[Label]) -> Vec<Vec<Label>> {
+    pub fn search(&self, element: impl IntoIterator<Item = Label>) -> Result<Vec<Vec<Label>>, TrieError> {
+        Ok(self.search_iter(element)?.collect())
+    }
+
+    /// Lazy, iterator form of `search`: yields every key that has `element` as a
+    /// prefix, computed on demand via a DFS over the matching subtree.
+    pub fn search_iter(
+        &self,
+        element: impl IntoIterator<Item = Label>,
+    ) -> Result<SearchIter<'_, Label, Value>, TrieError> {
+        let (node, prefix) = self.walk(element)?;
+        Ok(SearchIter {
+            stack: vec![],
+            prefix,
+            pending_root: Some(node),
+        })
+    }
+
+    /// Walks down to the node exactly matching `element`. If `element` ends partway
+    /// through a compressed edge, the rest of that edge is deterministic (it has no
+    /// branches), so it is baked into the returned prefix and the walk lands on the
+    /// node at the far end of the edge.
+    fn walk(
+        &self,
+        element: impl IntoIterator<Item = Label>,
+    ) -> Result<(&Node<Label, Value>, Vec<Label>), TrieError> {
         let mut node = &self.root;
-        let mut results = vec![];
         let mut prefix = vec![];
-        for label in element.iter() {
-            prefix.push(*label);
-            let child_opt = node.children.get(label);
-            if let Some(child) = child_opt {
-                node = child;
-                if node.is_leaf {
-                    results.push(prefix.clone());
+        let mut iter = element.into_iter();
+        while let Some(label) = iter.next() {
+            let child = node.children.get(&label).ok_or(TrieError::NoResultFound)?;
+            prefix.push(label);
+            let mut segment = child.segment.iter();
+            for &segment_label in segment.by_ref() {
+                match iter.next() {
+                    None => {
+                        prefix.push(segment_label);
+                        prefix.extend(segment.copied());
+                        return Ok((child, prefix));
+                    }
+                    Some(next_label) if next_label == segment_label => prefix.push(segment_label),
+                    Some(_) => return Err(TrieError::NoResultFound),
                 }
-            } else {
-                return results;
             }
+            node = child;
         }
-        results
+        Ok((node, prefix))
     }
 }
 
-impl<Label> Default for Trie<Label> {
+impl<Label: Eq + Hash + Copy, Value: Clone> Trie<Label, Value> {
+    /// Same as `search`, but also returns the value associated with every key.
+    pub fn search_with_values(
+        &self,
+        element: impl IntoIterator<Item = Label>,
+    ) -> Result<Vec<(Vec<Label>, Value)>, TrieError> {
+        let (node, prefix) = self.walk(element)?;
+        Ok(SearchIterWithValues {
+            stack: vec![],
+            prefix,
+            pending_root: Some(node),
+        }
+        .map(|(key, value)| (key, value.clone()))
+        .collect())
+    }
+}
+
+impl<Label: Eq + Hash + Copy, Value> Default for Trie<Label, Value> {
     fn default() -> Self {
         Trie {
-            has_search_index: false,
             root: Node::default(),
         }
     }
 }
 
+/// Builds the edge leading into `child`: its map key label followed by its
+/// segment (always empty unless the trie is compressed).
+fn edge_into<Label: Copy, Value>(label: Label, child: &Node<Label, Value>) -> Vec<Label> {
+    let mut edge = Vec::with_capacity(1 + child.segment.len());
+    edge.push(label);
+    edge.extend(child.segment.iter().copied());
+    edge
+}
+
+/// Lazy DFS over the completions of a `search`/`search_iter` query, see `Trie::search_iter`.
+pub struct SearchIter<'a, Label, Value> {
+    stack: Vec<(&'a Node<Label, Value>, usize, Vec<Label>)>,
+    prefix: Vec<Label>,
+    pending_root: Option<&'a Node<Label, Value>>,
+}
+
+impl<'a, Label: Eq + Hash + Copy, Value> Iterator for SearchIter<'a, Label, Value> {
+    type Item = Vec<Label>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(node) = self.pending_root.take() {
+            let depth = self.prefix.len();
+            for (&label, child) in node.children.iter() {
+                let edge = edge_into(label, child);
+                self.stack.push((child, depth + edge.len(), edge));
+            }
+            if node.value.is_some() {
+                return Some(self.prefix.clone());
+            }
+        }
+        while let Some((node, depth, edge)) = self.stack.pop() {
+            self.prefix.truncate(depth - edge.len());
+            self.prefix.extend(edge);
+            for (&label, child) in node.children.iter() {
+                let child_edge = edge_into(label, child);
+                self.stack.push((child, depth + child_edge.len(), child_edge));
+            }
+            if node.value.is_some() {
+                return Some(self.prefix.clone());
+            }
+        }
+        None
+    }
+}
+
+struct SearchIterWithValues<'a, Label, Value> {
+    stack: Vec<(&'a Node<Label, Value>, usize, Vec<Label>)>,
+    prefix: Vec<Label>,
+    pending_root: Option<&'a Node<Label, Value>>,
+}
+
+impl<'a, Label: Eq + Hash + Copy, Value> Iterator for SearchIterWithValues<'a, Label, Value> {
+    type Item = (Vec<Label>, &'a Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(node) = self.pending_root.take() {
+            let depth = self.prefix.len();
+            for (&label, child) in node.children.iter() {
+                let edge = edge_into(label, child);
+                self.stack.push((child, depth + edge.len(), edge));
+            }
+            if let Some(value) = &node.value {
+                return Some((self.prefix.clone(), value));
+            }
+        }
+        while let Some((node, depth, edge)) = self.stack.pop() {
+            self.prefix.truncate(depth - edge.len());
+            self.prefix.extend(edge);
+            for (&label, child) in node.children.iter() {
+                let child_edge = edge_into(label, child);
+                self.stack.push((child, depth + child_edge.len(), child_edge));
+            }
+            if let Some(value) = &node.value {
+                return Some((self.prefix.clone(), value));
+            }
+        }
+        None
+    }
+}
+
+/// Recursively collapses chains of single-child, non-terminal nodes under `node`
+/// into one edge carrying the chain's labels as a `segment`, see `Trie::compress`.
+fn compress_children<Label: Eq + Hash + Copy, Value>(node: &mut Node<Label, Value>) {
+    for child in node.children.values_mut() {
+        while child.value.is_none() && child.children.len() == 1 {
+            let (label, grandchild) = child.children.drain().next().expect("len() == 1");
+            child.segment.push(label);
+            child.segment.extend(grandchild.segment);
+            child.value = grandchild.value;
+            child.children = grandchild.children;
+        }
+        compress_children(child);
+    }
+}
+
 #[derive(Debug, Clone)]
-pub struct Node<Label> {
-    is_leaf: bool,
-    subwords: Vec<Vec<Label>>,
-    children: HashMap<Label, Node<Label>>,
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "Label: serde::Serialize, Value: serde::Serialize",
+        deserialize = "Label: Eq + std::hash::Hash + serde::Deserialize<'de>, Value: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Node<Label, Value> {
+    pub(crate) value: Option<Value>,
+    /// Extra labels absorbed from a chain of single-child, non-terminal
+    /// descendants when the trie is compressed (see `Trie::compress`).
+    /// Empty for a plain, uncompressed trie.
+    pub(crate) segment: Vec<Label>,
+    pub(crate) children: HashMap<Label, Node<Label, Value>>,
 }
 
-impl<Label> Default for Node<Label> {
+impl<Label, Value> Default for Node<Label, Value> {
     fn default() -> Self {
         Node {
-            is_leaf: false,
-            subwords: vec![],
+            value: None,
+            segment: vec![],
             children: HashMap::new(),
         }
     }
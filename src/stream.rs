@@ -0,0 +1,151 @@
+//! Streaming multi-pattern matching (Aho-Corasick-style) over the keys stored in
+//! a trie: feed one `Label` at a time and get back every stored key that ends at
+//! the current position, without re-running `search` from scratch each time.
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::{Node, Trie};
+
+const ROOT: usize = 0;
+
+struct StreamNode<Label> {
+    children: HashMap<Label, usize>,
+    fail: usize,
+    /// The key ending at this node, if this node's path is a stored key.
+    key: Option<Vec<Label>>,
+    /// Nearest ancestor along the failure chain (exclusive) that has a `key`.
+    output_link: Option<usize>,
+}
+
+/// A cursor over the Aho-Corasick automaton built by `Trie::build_stream_matcher`.
+/// Feed it one label at a time with `push`; it reports every stored key that ends
+/// at the position just fed, scanning a continuous stream without rewinding.
+pub struct StreamMatcher<Label> {
+    arena: Vec<StreamNode<Label>>,
+    current: usize,
+}
+
+impl<Label: Eq + Hash + Copy> StreamMatcher<Label> {
+    pub(crate) fn new<Value>(trie: &Trie<Label, Value>) -> Self {
+        let mut arena = vec![StreamNode {
+            children: HashMap::new(),
+            fail: ROOT,
+            key: trie.root.value.as_ref().map(|_| vec![]),
+            output_link: None,
+        }];
+        let mut prefix = vec![];
+        populate(&trie.root, &mut arena, ROOT, &mut prefix);
+        compute_fail_links(&mut arena);
+        StreamMatcher { arena, current: ROOT }
+    }
+
+    /// Resets the cursor to the start of a fresh stream.
+    pub fn reset(&mut self) {
+        self.current = ROOT;
+    }
+
+    /// Advances the matcher by one label, returning every stored key that ends at
+    /// the resulting position (the empty `Vec` if none do).
+    pub fn push(&mut self, label: Label) -> Vec<Vec<Label>> {
+        let mut u = self.current;
+        self.current = loop {
+            if let Some(&v) = self.arena[u].children.get(&label) {
+                break v;
+            }
+            if u == ROOT {
+                break ROOT;
+            }
+            u = self.arena[u].fail;
+        };
+
+        let mut matches = vec![];
+        let mut node = self.current;
+        loop {
+            if let Some(key) = &self.arena[node].key {
+                matches.push(key.clone());
+            }
+            match self.arena[node].output_link {
+                Some(next) => node = next,
+                None => break,
+            }
+        }
+        matches
+    }
+}
+
+/// Copies `src`'s subtree into `arena` under `arena_idx`, expanding any
+/// compressed multi-label edge (`Node::segment`) into a chain of single-label
+/// arena nodes, since the automaton advances by exactly one label per `push`.
+fn populate<Label: Eq + Hash + Copy, Value>(
+    src: &Node<Label, Value>,
+    arena: &mut Vec<StreamNode<Label>>,
+    arena_idx: usize,
+    prefix: &mut Vec<Label>,
+) {
+    for (&label, child) in src.children.iter() {
+        let base_len = prefix.len();
+        prefix.push(label);
+        let mut cur_idx = push_node(arena, prefix, child.segment.is_empty() && child.value.is_some());
+        arena[arena_idx].children.insert(label, cur_idx);
+
+        let segment_len = child.segment.len();
+        for (i, &segment_label) in child.segment.iter().enumerate() {
+            prefix.push(segment_label);
+            let is_last = i + 1 == segment_len;
+            let next_idx = push_node(arena, prefix, is_last && child.value.is_some());
+            arena[cur_idx].children.insert(segment_label, next_idx);
+            cur_idx = next_idx;
+        }
+
+        populate(child, arena, cur_idx, prefix);
+        prefix.truncate(base_len);
+    }
+}
+
+fn push_node<Label: Copy>(arena: &mut Vec<StreamNode<Label>>, prefix: &[Label], is_key: bool) -> usize {
+    let idx = arena.len();
+    arena.push(StreamNode {
+        children: HashMap::new(),
+        fail: ROOT,
+        key: is_key.then(|| prefix.to_vec()),
+        output_link: None,
+    });
+    idx
+}
+
+/// Standard Aho-Corasick construction: BFS over the arena computing, for each
+/// node, the longest proper suffix of its path that is also a path in the
+/// automaton (`fail`), and the nearest failure-chain ancestor with output
+/// (`output_link`), so `push` can report every match ending at a position in
+/// O(1) amortized per match.
+fn compute_fail_links<Label: Eq + Hash + Copy>(arena: &mut [StreamNode<Label>]) {
+    let mut queue = VecDeque::new();
+    let root_children: Vec<usize> = arena[ROOT].children.values().copied().collect();
+    let root_output = arena[ROOT].key.as_ref().map(|_| ROOT);
+    for idx in root_children {
+        arena[idx].fail = ROOT;
+        arena[idx].output_link = root_output;
+        queue.push_back(idx);
+    }
+
+    while let Some(u) = queue.pop_front() {
+        let children: Vec<(Label, usize)> = arena[u].children.iter().map(|(&l, &i)| (l, i)).collect();
+        for (label, v) in children {
+            let mut f = arena[u].fail;
+            while f != ROOT && !arena[f].children.contains_key(&label) {
+                f = arena[f].fail;
+            }
+            let fail_v = match arena[f].children.get(&label) {
+                Some(&w) if w != v => w,
+                _ => ROOT,
+            };
+            arena[v].fail = fail_v;
+            arena[v].output_link = if arena[fail_v].key.is_some() {
+                Some(fail_v)
+            } else {
+                arena[fail_v].output_link
+            };
+            queue.push_back(v);
+        }
+    }
+}